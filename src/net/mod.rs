@@ -0,0 +1,7 @@
+mod tcp;
+pub use self::tcp::TcpSocket;
+
+#[cfg(unix)]
+mod unix;
+#[cfg(unix)]
+pub use self::unix::{RecvFlags, SocketAddr, SocketAddrKind, UnixDatagram, UnixListener};
@@ -0,0 +1,2 @@
+mod socket;
+pub use self::socket::TcpSocket;
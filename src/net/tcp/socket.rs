@@ -0,0 +1,145 @@
+use std::io;
+use std::net::{self, SocketAddr};
+use std::time::Duration;
+
+#[cfg(unix)]
+use crate::sys::unix::tcp as sys;
+#[cfg(windows)]
+use crate::sys::windows::tcp as sys;
+
+pub use crate::sys::tcp::TcpKeepalive;
+
+/// A non-blocking TCP socket used to configure a stream or listener before
+/// it is connected or bound.
+///
+/// `TcpSocket` wraps the platform socket option calls in `crate::sys`,
+/// letting callers tune things like `SO_REUSEADDR` or `SO_REUSEPORT` before
+/// handing the descriptor off to a `std::net::TcpStream` (via [`connect`])
+/// or `std::net::TcpListener` (via [`listen`]).
+///
+/// [`connect`]: TcpSocket::connect
+/// [`listen`]: TcpSocket::listen
+pub struct TcpSocket {
+    sys: sys::TcpSocket,
+}
+
+impl TcpSocket {
+    /// Create a new socket configured for IPv4.
+    pub fn new_v4() -> io::Result<TcpSocket> {
+        sys::new_v4_socket().map(|sys| TcpSocket { sys })
+    }
+
+    /// Create a new socket configured for IPv6.
+    pub fn new_v6() -> io::Result<TcpSocket> {
+        sys::new_v6_socket().map(|sys| TcpSocket { sys })
+    }
+
+    /// Bind the socket to the given address.
+    pub fn bind(&self, addr: SocketAddr) -> io::Result<()> {
+        sys::bind(self.sys, addr)
+    }
+
+    /// Connect the socket to `addr`, consuming it and returning the
+    /// `std::net::TcpStream` that now owns the underlying socket.
+    pub fn connect(self, addr: SocketAddr) -> io::Result<net::TcpStream> {
+        sys::connect(self.sys, addr)
+    }
+
+    /// Convert the socket into a listener, consuming it.
+    pub fn listen(self, backlog: u32) -> io::Result<net::TcpListener> {
+        sys::listen(self.sys, backlog)
+    }
+
+    /// Get the local address this socket is bound to.
+    pub fn get_localaddr(&self) -> io::Result<SocketAddr> {
+        sys::get_localaddr(self.sys)
+    }
+
+    /// Set the value of `SO_REUSEADDR` on this socket.
+    pub fn set_reuseaddr(&self, reuseaddr: bool) -> io::Result<()> {
+        sys::set_reuseaddr(self.sys, reuseaddr)
+    }
+
+    /// Get the value of `SO_REUSEADDR` set on this socket.
+    pub fn get_reuseaddr(&self) -> io::Result<bool> {
+        sys::get_reuseaddr(self.sys)
+    }
+
+    /// Set the value of `SO_REUSEPORT` on this socket, allowing multiple
+    /// sockets to bind the same address/port so incoming connections can be
+    /// load balanced across them (e.g. one accepting thread per CPU).
+    ///
+    /// Windows has no distinct `SO_REUSEPORT`; there this returns
+    /// `ErrorKind::Unsupported`.
+    pub fn set_reuseport(&self, reuseport: bool) -> io::Result<()> {
+        sys::set_reuseport(self.sys, reuseport)
+    }
+
+    /// Get the value of `SO_REUSEPORT` set on this socket.
+    ///
+    /// Windows has no distinct `SO_REUSEPORT`; there this returns
+    /// `ErrorKind::Unsupported`.
+    pub fn get_reuseport(&self) -> io::Result<bool> {
+        sys::get_reuseport(self.sys)
+    }
+
+    /// Set `TCP_NODELAY`, disabling Nagle's algorithm when `true`.
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        sys::set_nodelay(self.sys, nodelay)
+    }
+
+    /// Get whether `TCP_NODELAY` is set on this socket.
+    pub fn get_nodelay(&self) -> io::Result<bool> {
+        sys::get_nodelay(self.sys)
+    }
+
+    /// Set `SO_KEEPALIVE`, applying a single duration to both the idle time
+    /// and the probe interval. See [`set_keepalive_params`] to configure
+    /// those independently (and, on Unix, the probe count).
+    ///
+    /// [`set_keepalive_params`]: TcpSocket::set_keepalive_params
+    pub fn set_keepalive(&self, dur: Option<Duration>) -> io::Result<()> {
+        sys::set_keepalive(self.sys, dur)
+    }
+
+    /// Get the current `SO_KEEPALIVE` duration, if any.
+    pub fn get_keepalive(&self) -> io::Result<Option<Duration>> {
+        sys::get_keepalive(self.sys)
+    }
+
+    /// Set detailed keepalive parameters: idle time, probe interval, and,
+    /// on Unix, probe count.
+    pub fn set_keepalive_params(&self, keepalive: &TcpKeepalive) -> io::Result<()> {
+        sys::set_keepalive_params(self.sys, keepalive)
+    }
+
+    /// Set `SO_LINGER`.
+    pub fn set_linger(&self, dur: Option<Duration>) -> io::Result<()> {
+        sys::set_linger(self.sys, dur)
+    }
+
+    /// Get `SO_LINGER`.
+    pub fn get_linger(&self) -> io::Result<Option<Duration>> {
+        sys::get_linger(self.sys)
+    }
+
+    /// Set `SO_SNDBUF`.
+    pub fn set_send_buffer_size(&self, size: u32) -> io::Result<()> {
+        sys::set_send_buffer_size(self.sys, size)
+    }
+
+    /// Get `SO_SNDBUF`.
+    pub fn get_send_buffer_size(&self) -> io::Result<u32> {
+        sys::get_send_buffer_size(self.sys)
+    }
+
+    /// Set `SO_RCVBUF`.
+    pub fn set_recv_buffer_size(&self, size: u32) -> io::Result<()> {
+        sys::set_recv_buffer_size(self.sys, size)
+    }
+
+    /// Get `SO_RCVBUF`.
+    pub fn get_recv_buffer_size(&self) -> io::Result<u32> {
+        sys::get_recv_buffer_size(self.sys)
+    }
+}
@@ -0,0 +1 @@
+pub use crate::sys::unix::uds::{RecvFlags, SocketAddr, SocketAddrKind, UnixDatagram, UnixListener};
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// Configuration for TCP keepalive probes, mirroring the options exposed by
+/// socket2's `TcpKeepalive`.
+///
+/// This is a single cross-platform type: each platform's `tcp` module
+/// consumes the `time`/`interval`/`retries` fields as far as its
+/// `setsockopt`/`WSAIoctl` surface allows (Windows, for instance, has no way
+/// to configure the probe count and rejects `retries` at the point of use).
+#[derive(Clone, Debug, Default)]
+pub struct TcpKeepalive {
+    pub(crate) time: Option<Duration>,
+    pub(crate) interval: Option<Duration>,
+    pub(crate) retries: Option<u32>,
+}
+
+impl TcpKeepalive {
+    pub fn new() -> TcpKeepalive {
+        TcpKeepalive::default()
+    }
+
+    pub fn with_time(self, time: Duration) -> TcpKeepalive {
+        TcpKeepalive {
+            time: Some(time),
+            ..self
+        }
+    }
+
+    pub fn with_interval(self, interval: Duration) -> TcpKeepalive {
+        TcpKeepalive {
+            interval: Some(interval),
+            ..self
+        }
+    }
+
+    pub fn with_retries(self, retries: u32) -> TcpKeepalive {
+        TcpKeepalive {
+            retries: Some(retries),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use std::io;
+use std::mem::size_of;
+use std::net::{self, SocketAddr};
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::time::Duration;
+
+use crate::sys::unix::net::{new_socket, socket_addr};
+use crate::sys::tcp::TcpKeepalive;
+
+pub(crate) type TcpSocket = libc::c_int;
+
+pub(crate) fn new_v4_socket() -> io::Result<TcpSocket> {
+    new_socket(libc::AF_INET, libc::SOCK_STREAM)
+}
+
+pub(crate) fn new_v6_socket() -> io::Result<TcpSocket> {
+    new_socket(libc::AF_INET6, libc::SOCK_STREAM)
+}
+
+pub(crate) fn bind(socket: TcpSocket, addr: SocketAddr) -> io::Result<()> {
+    let (raw_addr, raw_addr_length) = socket_addr(&addr);
+    syscall!(bind(socket, raw_addr.as_ptr(), raw_addr_length))?;
+    Ok(())
+}
+
+pub(crate) fn connect(socket: TcpSocket, addr: SocketAddr) -> io::Result<net::TcpStream> {
+    let (raw_addr, raw_addr_length) = socket_addr(&addr);
+
+    match syscall!(connect(socket, raw_addr.as_ptr(), raw_addr_length)) {
+        Err(err) if err.kind() != io::ErrorKind::WouldBlock => Err(err),
+        _ => Ok(unsafe { net::TcpStream::from_raw_fd(socket) }),
+    }
+}
+
+pub(crate) fn listen(socket: TcpSocket, backlog: u32) -> io::Result<net::TcpListener> {
+    use std::convert::TryInto;
+
+    let backlog = backlog.try_into().unwrap_or(i32::max_value());
+    syscall!(listen(socket, backlog))?;
+    Ok(unsafe { net::TcpListener::from_raw_fd(socket) })
+}
+
+pub(crate) fn close(socket: TcpSocket) {
+    let _ = unsafe { libc::close(socket) };
+}
+
+pub(crate) fn set_reuseaddr(socket: TcpSocket, reuseaddr: bool) -> io::Result<()> {
+    let val: libc::c_int = if reuseaddr { 1 } else { 0 };
+    syscall!(setsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEADDR,
+        &val as *const libc::c_int as *const libc::c_void,
+        size_of::<libc::c_int>() as libc::socklen_t,
+    ))?;
+    Ok(())
+}
+
+pub(crate) fn get_reuseaddr(socket: TcpSocket) -> io::Result<bool> {
+    let mut optval: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+
+    syscall!(getsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEADDR,
+        &mut optval as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+    Ok(optval != 0)
+}
+
+// `SO_REUSEPORT` lets multiple sockets bind the same address/port so that
+// incoming connections can be load balanced across them, e.g. one accepting
+// thread per CPU. Unlike `SO_REUSEADDR` this is a genuinely distinct option
+// on Unix, so it gets its own pair of accessors rather than piggybacking on
+// `set_reuseaddr`/`get_reuseaddr` above.
+pub(crate) fn set_reuseport(socket: TcpSocket, reuseport: bool) -> io::Result<()> {
+    let val: libc::c_int = if reuseport { 1 } else { 0 };
+    syscall!(setsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEPORT,
+        &val as *const libc::c_int as *const libc::c_void,
+        size_of::<libc::c_int>() as libc::socklen_t,
+    ))?;
+    Ok(())
+}
+
+pub(crate) fn get_reuseport(socket: TcpSocket) -> io::Result<bool> {
+    let mut optval: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+
+    syscall!(getsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_REUSEPORT,
+        &mut optval as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+    Ok(optval != 0)
+}
+
+pub(crate) fn set_nodelay(socket: TcpSocket, nodelay: bool) -> io::Result<()> {
+    let val: libc::c_int = if nodelay { 1 } else { 0 };
+    syscall!(setsockopt(
+        socket,
+        libc::IPPROTO_TCP,
+        libc::TCP_NODELAY,
+        &val as *const libc::c_int as *const libc::c_void,
+        size_of::<libc::c_int>() as libc::socklen_t,
+    ))?;
+    Ok(())
+}
+
+pub(crate) fn get_nodelay(socket: TcpSocket) -> io::Result<bool> {
+    let mut optval: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+
+    syscall!(getsockopt(
+        socket,
+        libc::IPPROTO_TCP,
+        libc::TCP_NODELAY,
+        &mut optval as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+    Ok(optval != 0)
+}
+
+pub(crate) fn get_localaddr(socket: TcpSocket) -> io::Result<SocketAddr> {
+    crate::sys::unix::net::getsockname(socket)
+}
+
+pub(crate) fn set_linger(socket: TcpSocket, dur: Option<Duration>) -> io::Result<()> {
+    let val = libc::linger {
+        l_onoff: if dur.is_some() { 1 } else { 0 },
+        l_linger: dur.map(|dur| dur.as_secs() as libc::c_int).unwrap_or_default(),
+    };
+
+    syscall!(setsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_LINGER,
+        &val as *const libc::linger as *const libc::c_void,
+        size_of::<libc::linger>() as libc::socklen_t,
+    ))?;
+    Ok(())
+}
+
+pub(crate) fn get_linger(socket: TcpSocket) -> io::Result<Option<Duration>> {
+    let mut val: libc::linger = unsafe { std::mem::zeroed() };
+    let mut optlen = size_of::<libc::linger>() as libc::socklen_t;
+
+    syscall!(getsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_LINGER,
+        &mut val as *mut libc::linger as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+
+    if val.l_onoff == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(Duration::from_secs(val.l_linger as u64)))
+    }
+}
+
+pub(crate) fn set_send_buffer_size(socket: TcpSocket, size: u32) -> io::Result<()> {
+    set_socket_opt(socket, libc::SOL_SOCKET, libc::SO_SNDBUF, size as libc::c_int)
+}
+
+pub(crate) fn get_send_buffer_size(socket: TcpSocket) -> io::Result<u32> {
+    get_socket_opt(socket, libc::SOL_SOCKET, libc::SO_SNDBUF)
+}
+
+pub(crate) fn set_recv_buffer_size(socket: TcpSocket, size: u32) -> io::Result<()> {
+    set_socket_opt(socket, libc::SOL_SOCKET, libc::SO_RCVBUF, size as libc::c_int)
+}
+
+pub(crate) fn get_recv_buffer_size(socket: TcpSocket) -> io::Result<u32> {
+    get_socket_opt(socket, libc::SOL_SOCKET, libc::SO_RCVBUF)
+}
+
+fn get_socket_opt(socket: TcpSocket, level: libc::c_int, name: libc::c_int) -> io::Result<u32> {
+    let mut optval: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+    syscall!(getsockopt(
+        socket,
+        level,
+        name,
+        &mut optval as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+    Ok(optval as u32)
+}
+
+fn set_socket_opt(
+    socket: TcpSocket,
+    level: libc::c_int,
+    name: libc::c_int,
+    val: libc::c_int,
+) -> io::Result<()> {
+    syscall!(setsockopt(
+        socket,
+        level,
+        name,
+        &val as *const libc::c_int as *const libc::c_void,
+        size_of::<libc::c_int>() as libc::socklen_t,
+    ))?;
+    Ok(())
+}
+
+pub(crate) fn set_keepalive_params(socket: TcpSocket, keepalive: &TcpKeepalive) -> io::Result<()> {
+    let enable = keepalive.time.is_some() || keepalive.interval.is_some() || keepalive.retries.is_some();
+    set_socket_opt(socket, libc::SOL_SOCKET, libc::SO_KEEPALIVE, enable as libc::c_int)?;
+
+    if let Some(time) = keepalive.time {
+        let secs = time.as_secs().min(libc::c_int::max_value() as u64) as libc::c_int;
+        set_socket_opt(socket, libc::IPPROTO_TCP, KEEPALIVE_TIME_OPT, secs)?;
+    }
+    if let Some(interval) = keepalive.interval {
+        let secs = interval.as_secs().min(libc::c_int::max_value() as u64) as libc::c_int;
+        set_socket_opt(socket, libc::IPPROTO_TCP, libc::TCP_KEEPINTVL, secs)?;
+    }
+    if let Some(retries) = keepalive.retries {
+        let retries = retries.min(libc::c_int::max_value() as u32) as libc::c_int;
+        set_socket_opt(socket, libc::IPPROTO_TCP, libc::TCP_KEEPCNT, retries)?;
+    }
+    Ok(())
+}
+
+// Linux/Android spell the idle-time option `TCP_KEEPIDLE`; macOS and iOS
+// spell it `TCP_KEEPALIVE`. No other target defines this constant.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const KEEPALIVE_TIME_OPT: libc::c_int = libc::TCP_KEEPIDLE;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const KEEPALIVE_TIME_OPT: libc::c_int = libc::TCP_KEEPALIVE;
+
+pub(crate) fn set_keepalive(socket: TcpSocket, dur: Option<Duration>) -> io::Result<()> {
+    let keepalive = match dur {
+        Some(dur) => TcpKeepalive::new().with_time(dur).with_interval(dur),
+        None => TcpKeepalive::new(),
+    };
+    set_keepalive_params(socket, &keepalive)
+}
+
+pub(crate) fn get_keepalive(socket: TcpSocket) -> io::Result<Option<Duration>> {
+    let mut optval: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+
+    syscall!(getsockopt(
+        socket,
+        libc::SOL_SOCKET,
+        libc::SO_KEEPALIVE,
+        &mut optval as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+
+    if optval == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(get_keepalive_time(socket)?))
+    }
+}
+
+fn get_keepalive_time(socket: TcpSocket) -> io::Result<Duration> {
+    let mut secs: libc::c_int = 0;
+    let mut optlen = size_of::<libc::c_int>() as libc::socklen_t;
+    syscall!(getsockopt(
+        socket,
+        libc::IPPROTO_TCP,
+        KEEPALIVE_TIME_OPT,
+        &mut secs as *mut libc::c_int as *mut libc::c_void,
+        &mut optlen,
+    ))?;
+    Ok(Duration::from_secs(secs as u64))
+}
+
+pub(crate) fn accept(listener: &net::TcpListener) -> io::Result<(net::TcpStream, SocketAddr)> {
+    let fd = listener.as_raw_fd();
+    let (stream_fd, addr) = crate::sys::unix::net::accept(fd)?;
+    Ok((unsafe { net::TcpStream::from_raw_fd(stream_fd) }, addr))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        get_keepalive, get_linger, get_nodelay, get_recv_buffer_size, get_reuseport, get_send_buffer_size,
+        new_v4_socket, set_keepalive_params, set_linger, set_nodelay, set_recv_buffer_size, set_reuseport,
+        set_send_buffer_size,
+    };
+    use crate::sys::tcp::TcpKeepalive;
+
+    #[test]
+    fn reuseport_round_trip() {
+        let socket = new_v4_socket().unwrap();
+
+        assert!(!get_reuseport(socket).unwrap());
+
+        set_reuseport(socket, true).unwrap();
+        assert!(get_reuseport(socket).unwrap());
+
+        set_reuseport(socket, false).unwrap();
+        assert!(!get_reuseport(socket).unwrap());
+
+        unsafe { libc::close(socket) };
+    }
+
+    #[test]
+    fn nodelay_round_trip() {
+        let socket = new_v4_socket().unwrap();
+
+        set_nodelay(socket, true).unwrap();
+        assert!(get_nodelay(socket).unwrap());
+
+        set_nodelay(socket, false).unwrap();
+        assert!(!get_nodelay(socket).unwrap());
+
+        unsafe { libc::close(socket) };
+    }
+
+    #[test]
+    fn linger_round_trip() {
+        let socket = new_v4_socket().unwrap();
+
+        assert_eq!(get_linger(socket).unwrap(), None);
+
+        set_linger(socket, Some(Duration::from_secs(7))).unwrap();
+        assert_eq!(get_linger(socket).unwrap(), Some(Duration::from_secs(7)));
+
+        set_linger(socket, None).unwrap();
+        assert_eq!(get_linger(socket).unwrap(), None);
+
+        unsafe { libc::close(socket) };
+    }
+
+    #[test]
+    fn buffer_size_round_trip() {
+        let socket = new_v4_socket().unwrap();
+
+        set_send_buffer_size(socket, 4096).unwrap();
+        assert!(get_send_buffer_size(socket).unwrap() >= 4096);
+
+        set_recv_buffer_size(socket, 4096).unwrap();
+        assert!(get_recv_buffer_size(socket).unwrap() >= 4096);
+
+        unsafe { libc::close(socket) };
+    }
+
+    #[test]
+    fn keepalive_params_round_trip() {
+        let socket = new_v4_socket().unwrap();
+
+        assert_eq!(get_keepalive(socket).unwrap(), None);
+
+        let keepalive = TcpKeepalive::new()
+            .with_time(Duration::from_secs(30))
+            .with_interval(Duration::from_secs(5))
+            .with_retries(4);
+        set_keepalive_params(socket, &keepalive).unwrap();
+        assert_eq!(get_keepalive(socket).unwrap(), Some(Duration::from_secs(30)));
+
+        unsafe { libc::close(socket) };
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::{self, IoSliceMut};
+use std::mem::MaybeUninit;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use std::path::Path;
+
+use crate::sys::unix::net::new_socket;
+
+use super::{
+    recv_vectored_with_flags as sys_recv_vectored_with_flags, socket_addr_kind, RecvFlags, SocketAddr,
+    SocketAddrKind,
+};
+
+/// A non-blocking Unix domain datagram socket.
+pub struct UnixDatagram {
+    fd: RawFd,
+}
+
+impl UnixDatagram {
+    /// Bind to a filesystem pathname address.
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixDatagram> {
+        UnixDatagram::bind_addr(SocketAddrKind::Pathname(path.as_ref())).map(|(socket, _)| socket)
+    }
+
+    /// Bind to a pathname, abstract-namespace, or unnamed (autobind)
+    /// address, returning the socket along with the address it ended up
+    /// bound to.
+    ///
+    /// For `SocketAddrKind::Unnamed`, the kernel only assigns the actual
+    /// abstract name once `bind` has run, so the returned `SocketAddr` is
+    /// read back via `local_addr` rather than derived from `kind` itself;
+    /// callers who need that name don't have to remember to ask for it
+    /// separately.
+    pub fn bind_addr(kind: SocketAddrKind<'_>) -> io::Result<(UnixDatagram, SocketAddr)> {
+        let (raw_addr, raw_addr_length) = socket_addr_kind(kind)?;
+        let socket = new_socket(libc::AF_UNIX, libc::SOCK_DGRAM)?;
+
+        syscall!(bind(
+            socket,
+            &raw_addr as *const libc::sockaddr_un as *const libc::sockaddr,
+            raw_addr_length,
+        ))?;
+
+        let datagram = UnixDatagram { fd: socket };
+        let addr = datagram.local_addr()?;
+        Ok((datagram, addr))
+    }
+
+    /// Returns the local address this socket is bound to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        let mut sockaddr = unsafe { MaybeUninit::<libc::sockaddr_un>::zeroed().assume_init() };
+        let mut socklen = std::mem::size_of::<libc::sockaddr_un>() as libc::socklen_t;
+
+        syscall!(getsockname(
+            self.fd,
+            &mut sockaddr as *mut libc::sockaddr_un as *mut libc::sockaddr,
+            &mut socklen,
+        ))?;
+
+        Ok(SocketAddr::from_parts(sockaddr, socklen))
+    }
+
+    /// Receive a datagram into `bufs`, also reporting via the returned
+    /// `RecvFlags` whether the datagram was truncated because `bufs` was
+    /// too small to hold it, rather than leaving the caller to guess from
+    /// the returned length.
+    pub fn recv_vectored_with_flags(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> io::Result<(usize, RecvFlags)> {
+        sys_recv_vectored_with_flags(self.fd, bufs)
+    }
+}
+
+impl AsRawFd for UnixDatagram {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl IntoRawFd for UnixDatagram {
+    fn into_raw_fd(self) -> RawFd {
+        let fd = self.fd;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl FromRawFd for UnixDatagram {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixDatagram {
+        UnixDatagram { fd }
+    }
+}
+
+impl Drop for UnixDatagram {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::IoSliceMut;
+    use std::os::unix::io::FromRawFd;
+
+    use super::UnixDatagram;
+
+    #[test]
+    fn recv_vectored_with_flags_reports_truncation() {
+        let mut fds = [0; 2];
+        assert_eq!(
+            unsafe { libc::socketpair(libc::AF_UNIX, libc::SOCK_DGRAM, 0, fds.as_mut_ptr()) },
+            0
+        );
+        let sender = fds[0];
+        let receiver = unsafe { UnixDatagram::from_raw_fd(fds[1]) };
+
+        let payload = [1u8; 16];
+        let sent = unsafe { libc::send(sender, payload.as_ptr() as *const libc::c_void, payload.len(), 0) };
+        assert_eq!(sent, payload.len() as isize);
+
+        let mut small = [0u8; 4];
+        let mut bufs = [IoSliceMut::new(&mut small)];
+        let (n, flags) = receiver.recv_vectored_with_flags(&mut bufs).unwrap();
+        assert_eq!(n, small.len());
+        assert!(flags.is_truncated());
+
+        unsafe { libc::close(sender) };
+    }
+}
@@ -0,0 +1,146 @@
+use std::ffi::OsStr;
+use std::io;
+use std::mem::MaybeUninit;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use std::path::Path;
+
+use crate::sys::unix::net::new_socket;
+
+use super::{path_offset, socket_addr_kind, SocketAddrKind};
+
+/// An address associated with a Unix socket, as returned by
+/// [`UnixListener::local_addr`].
+///
+/// A `SocketAddr` may name a conventional filesystem path, a Linux
+/// abstract-namespace name, or nothing at all ("unnamed", e.g. because the
+/// socket hasn't been bound). A socket bound via
+/// [`SocketAddrKind::Unnamed`] reads back from `local_addr` as the
+/// abstract-namespace name the kernel assigned to it.
+#[derive(Clone)]
+pub struct SocketAddr {
+    sockaddr: libc::sockaddr_un,
+    socklen: libc::socklen_t,
+}
+
+impl SocketAddr {
+    pub(crate) fn from_parts(sockaddr: libc::sockaddr_un, socklen: libc::socklen_t) -> SocketAddr {
+        SocketAddr { sockaddr, socklen }
+    }
+
+    fn name_bytes(&self) -> &[u8] {
+        let offset = path_offset(&self.sockaddr);
+        let len = self.socklen as usize - offset;
+        unsafe { std::slice::from_raw_parts(self.sockaddr.sun_path.as_ptr() as *const u8, len) }
+    }
+
+    /// Returns `true` if this address has no name, i.e. `socklen` carries no
+    /// bytes beyond the `sockaddr_un` header.
+    pub fn is_unnamed(&self) -> bool {
+        self.socklen as usize == path_offset(&self.sockaddr)
+    }
+
+    /// Returns `true` if this is a Linux abstract-namespace address.
+    pub fn is_abstract_namespace(&self) -> bool {
+        !self.is_unnamed() && self.name_bytes()[0] == 0
+    }
+
+    /// Returns the filesystem path this address names, if any.
+    pub fn as_pathname(&self) -> Option<&Path> {
+        if self.is_unnamed() || self.is_abstract_namespace() {
+            return None;
+        }
+        // Pathname addresses are null-terminated; drop the terminator.
+        let bytes = self.name_bytes();
+        let bytes = &bytes[..bytes.len() - 1];
+        Some(Path::new(OsStr::from_bytes(bytes)))
+    }
+
+    /// Returns the raw abstract-namespace name, including the leading NUL
+    /// byte that marks it as abstract, if any.
+    pub fn as_abstract_namespace(&self) -> Option<&[u8]> {
+        if self.is_abstract_namespace() {
+            Some(self.name_bytes())
+        } else {
+            None
+        }
+    }
+}
+
+/// A non-blocking Unix domain socket listener.
+pub struct UnixListener {
+    fd: RawFd,
+}
+
+impl UnixListener {
+    /// Bind to a filesystem pathname address.
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixListener> {
+        UnixListener::bind_addr(SocketAddrKind::Pathname(path.as_ref())).map(|(listener, _)| listener)
+    }
+
+    /// Bind to a pathname, abstract-namespace, or unnamed (autobind)
+    /// address, returning the listener along with the address it ended up
+    /// bound to.
+    ///
+    /// For `SocketAddrKind::Unnamed`, the kernel only assigns the actual
+    /// abstract name once `bind` has run, so the returned `SocketAddr` is
+    /// read back via `local_addr` rather than derived from `kind` itself;
+    /// callers who need that name don't have to remember to ask for it
+    /// separately.
+    pub fn bind_addr(kind: SocketAddrKind<'_>) -> io::Result<(UnixListener, SocketAddr)> {
+        let (raw_addr, raw_addr_length) = socket_addr_kind(kind)?;
+
+        let socket = new_socket(libc::AF_UNIX, libc::SOCK_STREAM)?;
+        let listener = UnixListener { fd: socket };
+
+        syscall!(bind(
+            socket,
+            &raw_addr as *const libc::sockaddr_un as *const libc::sockaddr,
+            raw_addr_length,
+        ))
+        .and_then(|_| syscall!(listen(socket, 1024)))?;
+
+        let addr = listener.local_addr()?;
+        Ok((listener, addr))
+    }
+
+    /// Returns the local address this listener is bound to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        let mut sockaddr = unsafe { MaybeUninit::<libc::sockaddr_un>::zeroed().assume_init() };
+        let mut socklen = std::mem::size_of::<libc::sockaddr_un>() as libc::socklen_t;
+
+        syscall!(getsockname(
+            self.fd,
+            &mut sockaddr as *mut libc::sockaddr_un as *mut libc::sockaddr,
+            &mut socklen,
+        ))?;
+
+        Ok(SocketAddr::from_parts(sockaddr, socklen))
+    }
+}
+
+impl AsRawFd for UnixListener {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl IntoRawFd for UnixListener {
+    fn into_raw_fd(self) -> RawFd {
+        let fd = self.fd;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl FromRawFd for UnixListener {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixListener {
+        UnixListener { fd }
+    }
+}
+
+impl Drop for UnixListener {
+    fn drop(&mut self) {
+        let _ = unsafe { libc::close(self.fd) };
+    }
+}
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::io::IoSliceMut;
 use std::os::unix::ffi::OsStrExt;
 use std::os::unix::io::RawFd;
 use std::path::Path;
@@ -13,7 +14,22 @@ pub use self::listener::{SocketAddr, UnixListener};
 mod stream;
 pub use self::stream::UnixStream;
 
+/// Distinguishes the three flavors of `sockaddr_un` address this module can
+/// build: a conventional filesystem pathname, a Linux abstract-namespace
+/// name (the leading NUL byte that marks it as abstract is part of `name`,
+/// matching how the kernel itself represents it in `sun_path`), or an
+/// unnamed address that asks the kernel to autobind a unique abstract name.
+pub enum SocketAddrKind<'a> {
+    Pathname(&'a Path),
+    Abstract(&'a [u8]),
+    Unnamed,
+}
+
 pub fn socket_addr(path: &Path) -> io::Result<(libc::sockaddr_un, libc::socklen_t)> {
+    socket_addr_kind(SocketAddrKind::Pathname(path))
+}
+
+pub fn socket_addr_kind(kind: SocketAddrKind<'_>) -> io::Result<(libc::sockaddr_un, libc::socklen_t)> {
     let sockaddr = mem::MaybeUninit::<libc::sockaddr_un>::zeroed();
 
     // This is safe to assume because a `libc::sockaddr_un` filled with `0`
@@ -28,16 +44,27 @@ pub fn socket_addr(path: &Path) -> io::Result<(libc::sockaddr_un, libc::socklen_
 
     sockaddr.sun_family = libc::AF_UNIX as libc::sa_family_t;
 
-    let bytes = path.as_os_str().as_bytes();
-    match (bytes.get(0), bytes.len().cmp(&sockaddr.sun_path.len())) {
-        // Abstract paths don't need a null terminator
-        (Some(&0), Ordering::Greater) => {
+    let offset = path_offset(&sockaddr);
+
+    let (bytes, is_abstract) = match kind {
+        // An unnamed address has no bytes in `sun_path` at all; `socklen` is
+        // just the offset, telling the kernel to assign a unique abstract
+        // name on bind.
+        SocketAddrKind::Unnamed => return Ok((sockaddr, offset as libc::socklen_t)),
+        SocketAddrKind::Pathname(path) => (path.as_os_str().as_bytes(), false),
+        SocketAddrKind::Abstract(name) => (name, true),
+    };
+
+    match (is_abstract, bytes.len().cmp(&sockaddr.sun_path.len())) {
+        // Abstract paths don't need a null terminator, so filling
+        // `sun_path` entirely is allowed.
+        (true, Ordering::Greater) => {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "path must be no longer than libc::sockaddr_un.sun_path",
             ));
         }
-        (_, Ordering::Greater) | (_, Ordering::Equal) => {
+        (false, Ordering::Greater) | (false, Ordering::Equal) => {
             return Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "path must be shorter than libc::sockaddr_un.sun_path",
@@ -50,14 +77,12 @@ pub fn socket_addr(path: &Path) -> io::Result<(libc::sockaddr_un, libc::socklen_
         *dst = *src as libc::c_char;
     }
 
-    let offset = path_offset(&sockaddr);
     let mut socklen = offset + bytes.len();
 
-    match bytes.get(0) {
-        // The struct has already been zeroes so the null byte for pathname
+    if !is_abstract {
+        // The struct has already been zeroed so the null byte for pathname
         // addresses is already there.
-        Some(&0) | None => {}
-        Some(_) => socklen += 1,
+        socklen += 1;
     }
 
     Ok((sockaddr, socklen as libc::socklen_t))
@@ -75,6 +100,32 @@ pub fn path_offset(sockaddr: &libc::sockaddr_un) -> usize {
     path - base
 }
 
+/// Flags describing a received datagram, mirroring socket2's `RecvFlags`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecvFlags(libc::c_int);
+
+impl RecvFlags {
+    /// Returns `true` if the datagram was larger than the buffer(s) it was
+    /// received into, and so was truncated (`MSG_TRUNC`).
+    pub fn is_truncated(self) -> bool {
+        self.0 & libc::MSG_TRUNC != 0
+    }
+}
+
+/// Like `recv_vectored`, but also reports whether the datagram was
+/// truncated via the returned `RecvFlags`.
+pub(crate) fn recv_vectored_with_flags(
+    socket: RawFd,
+    bufs: &mut [IoSliceMut<'_>],
+) -> io::Result<(usize, RecvFlags)> {
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = bufs.as_mut_ptr() as *mut libc::iovec;
+    msg.msg_iovlen = bufs.len() as _;
+
+    let n = syscall!(recvmsg(socket, &mut msg, 0))?;
+    Ok((n as usize, RecvFlags(msg.msg_flags)))
+}
+
 fn pair_descriptors(mut fds: [RawFd; 2], flags: i32) -> io::Result<()> {
     #[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "solaris")))]
     let flags = flags | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC;
@@ -98,9 +149,8 @@ fn pair_descriptors(mut fds: [RawFd; 2], flags: i32) -> io::Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::{path_offset, socket_addr};
+    use super::{path_offset, socket_addr, socket_addr_kind, SocketAddrKind};
     use std::path::Path;
-    use std::str;
 
     // Assert `socklen` equals 16 (on Linux):
     //   - 13 bytes for path length
@@ -127,11 +177,32 @@ mod tests {
 
         // Abstract addresses do not have a null terminator, so `socklen` is
         // expected to be `PATH_LEN` + `offset`.
-        let abstract_path = str::from_utf8(PATH).unwrap();
-        let path = Path::new(abstract_path);
-        let (sockaddr, actual) = socket_addr(path).unwrap();
+        let (sockaddr, actual) = socket_addr_kind(SocketAddrKind::Abstract(PATH)).unwrap();
         let offset = path_offset(&sockaddr);
         let expected = PATH_LEN + offset;
         assert_eq!(expected as libc::socklen_t, actual)
     }
+
+    #[test]
+    fn abstract_address_fills_sun_path() {
+        // An abstract name may occupy the whole of `sun_path`, since unlike
+        // a pathname address it needs no trailing null terminator.
+        let (probe, _) = socket_addr_kind(SocketAddrKind::Unnamed).unwrap();
+        let max_len = probe.sun_path.len();
+        let name: Vec<u8> = std::iter::once(0).chain(vec![b'x'; max_len - 1]).collect();
+
+        let (sockaddr, actual) = socket_addr_kind(SocketAddrKind::Abstract(&name)).unwrap();
+        let offset = path_offset(&sockaddr);
+        assert_eq!((max_len + offset) as libc::socklen_t, actual);
+    }
+
+    #[test]
+    fn unnamed_address() {
+        // An unnamed address has no bytes in `sun_path`; `socklen` is just
+        // the offset, which tells the kernel to assign a unique abstract
+        // name on bind.
+        let (sockaddr, actual) = socket_addr_kind(SocketAddrKind::Unnamed).unwrap();
+        let offset = path_offset(&sockaddr);
+        assert_eq!(offset as libc::socklen_t, actual);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::io::{self, IoSliceMut};
+
+use winapi::shared::minwindef::{DWORD, LPDWORD};
+use winapi::shared::ws2def::{LPWSABUF, WSABUF};
+use winapi::shared::winerror::WSAEMSGSIZE;
+use winapi::um::winsock2::{WSAGetLastError, WSARecvFrom, SOCKET, SOCKET_ERROR};
+
+// Windows has no `MSG_TRUNC` equivalent; a truncated datagram is instead
+// reported as the `WSAEMSGSIZE` error from `WSARecvFrom`. This bit mirrors
+// `MSG_TRUNC` internally so `RecvFlags` behaves the same on both platforms.
+const TRUNCATED: DWORD = 0x1;
+
+/// Flags describing a received datagram, mirroring socket2's `RecvFlags`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecvFlags(DWORD);
+
+impl RecvFlags {
+    /// Returns `true` if the datagram was larger than the buffer(s) it was
+    /// received into, and so was truncated.
+    pub fn is_truncated(self) -> bool {
+        self.0 & TRUNCATED != 0
+    }
+}
+
+/// Like `recv_vectored`, but also reports whether the datagram was
+/// truncated via the returned `RecvFlags`.
+///
+/// Takes a raw `SOCKET` rather than `crate::sys::windows::tcp::TcpSocket`:
+/// this helper is for datagram sockets (UDP, not TCP), so it has no business
+/// depending on the `tcp` module.
+pub(crate) fn recv_vectored_with_flags(
+    socket: SOCKET,
+    bufs: &mut [IoSliceMut<'_>],
+) -> io::Result<(usize, RecvFlags)> {
+    let mut wsabufs: Vec<WSABUF> = bufs
+        .iter_mut()
+        .map(|buf| WSABUF {
+            len: buf.len() as u32,
+            buf: buf.as_mut_ptr() as *mut _,
+        })
+        .collect();
+
+    let mut nread: DWORD = 0;
+    let mut flags: DWORD = 0;
+
+    let res = unsafe {
+        WSARecvFrom(
+            socket,
+            wsabufs.as_mut_ptr() as LPWSABUF,
+            wsabufs.len() as DWORD,
+            &mut nread as LPDWORD,
+            &mut flags as LPDWORD,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            None,
+        )
+    };
+
+    if res != SOCKET_ERROR {
+        return Ok((nread as usize, RecvFlags(flags)));
+    }
+
+    if unsafe { WSAGetLastError() } == WSAEMSGSIZE {
+        Ok((nread as usize, RecvFlags(TRUNCATED)))
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -8,7 +8,7 @@ use std::os::windows::io::FromRawSocket;
 use std::os::windows::raw::SOCKET as StdSocket; // winapi uses usize, stdlib uses u32/u64.
 
 use winapi::ctypes::{c_char, c_int, c_ushort, c_ulong};
-use winapi::shared::ws2def::{SOCKADDR_STORAGE, AF_INET, SOCKADDR_IN};
+use winapi::shared::ws2def::{SOCKADDR_STORAGE, AF_INET, SOCKADDR_IN, IPPROTO_TCP, TCP_NODELAY};
 use winapi::shared::ws2ipdef::SOCKADDR_IN6_LH;
 use winapi::shared::mstcpip;
 
@@ -19,6 +19,7 @@ use winapi::um::winsock2::{
 };
 
 use crate::sys::windows::net::{init, new_socket, socket_addr};
+use crate::sys::tcp::TcpKeepalive;
 
 pub(crate) type TcpSocket = SOCKET;
 
@@ -109,6 +110,54 @@ pub(crate) fn get_reuseaddr(socket: TcpSocket) -> io::Result<bool> {
     }
 }
 
+// Windows has no distinct `SO_REUSEPORT`; `SO_REUSEADDR` already permits
+// rebinding an in-use address/port on this platform, so there is nothing
+// additional to toggle here.
+pub(crate) fn set_reuseport(_socket: TcpSocket, _reuseport: bool) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "SO_REUSEPORT is not supported on Windows",
+    ))
+}
+
+pub(crate) fn get_reuseport(_socket: TcpSocket) -> io::Result<bool> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "SO_REUSEPORT is not supported on Windows",
+    ))
+}
+
+pub(crate) fn set_nodelay(socket: TcpSocket, nodelay: bool) -> io::Result<()> {
+    let val: BOOL = if nodelay { TRUE } else { FALSE };
+
+    match unsafe { setsockopt(
+        socket,
+        IPPROTO_TCP as c_int,
+        TCP_NODELAY,
+        &val as *const _ as *const c_char,
+        size_of::<BOOL>() as c_int,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn get_nodelay(socket: TcpSocket) -> io::Result<bool> {
+    let mut optval: c_char = 0;
+    let mut optlen = size_of::<BOOL>() as c_int;
+
+    match unsafe { getsockopt(
+        socket,
+        IPPROTO_TCP as c_int,
+        TCP_NODELAY,
+        &mut optval as *mut _ as *mut _,
+        &mut optlen,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(optval != 0),
+    }
+}
+
 pub(crate) fn get_localaddr(socket: TcpSocket) -> io::Result<SocketAddr> {
     let mut addr: SOCKADDR_STORAGE = unsafe { std::mem::zeroed() };
     let mut length = std::mem::size_of_val(&addr) as c_int;
@@ -152,25 +201,118 @@ pub(crate) fn set_linger(socket: TcpSocket, dur: Option<Duration>) -> io::Result
     }
 }
 
-pub(crate) fn set_keepalive(socket: TcpSocket, dur: Option<Duration>) -> io::Result<()> {
-    // Windows takes the keepalive timeout as a u32 of milliseconds.
-    let dur_ms = dur.map(|dur| {
-        let ms = dur.as_millis();
-        ms.try_into().ok().unwrap_or_else(i32::max_value)
-    }).unwrap_or(0);
-
-    let keepalive = mstcpip::tcp_keepalive {
-        onoff: dur.is_some() as c_ulong,
-        keepalivetime: dur_ms as c_ulong,
-        keepaliveinterval: dur_ms as c_ulong,
+fn dur_to_ms(dur: Duration) -> c_ulong {
+    dur.as_millis().try_into().ok().unwrap_or_else(u32::max_value)
+}
+
+pub(crate) fn get_linger(socket: TcpSocket) -> io::Result<Option<Duration>> {
+    let mut val: linger = unsafe { std::mem::zeroed() };
+    let mut optlen = size_of::<linger>() as c_int;
+
+    match unsafe { getsockopt(
+        socket,
+        SOL_SOCKET,
+        SO_LINGER,
+        &mut val as *mut _ as *mut _,
+        &mut optlen,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => {
+            if val.l_onoff == 0 {
+                Ok(None)
+            } else {
+                Ok(Some(Duration::from_secs(val.l_linger as u64)))
+            }
+        }
+    }
+}
+
+pub(crate) fn set_send_buffer_size(socket: TcpSocket, size: u32) -> io::Result<()> {
+    let val = size as c_int;
+    match unsafe { setsockopt(
+        socket,
+        SOL_SOCKET,
+        winsock2::SO_SNDBUF,
+        &val as *const _ as *const c_char,
+        size_of::<c_int>() as c_int,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn get_send_buffer_size(socket: TcpSocket) -> io::Result<u32> {
+    let mut optval: c_int = 0;
+    let mut optlen = size_of::<c_int>() as c_int;
+
+    match unsafe { getsockopt(
+        socket,
+        SOL_SOCKET,
+        winsock2::SO_SNDBUF,
+        &mut optval as *mut _ as *mut _,
+        &mut optlen,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(optval as u32),
+    }
+}
+
+pub(crate) fn set_recv_buffer_size(socket: TcpSocket, size: u32) -> io::Result<()> {
+    let val = size as c_int;
+    match unsafe { setsockopt(
+        socket,
+        SOL_SOCKET,
+        winsock2::SO_RCVBUF,
+        &val as *const _ as *const c_char,
+        size_of::<c_int>() as c_int,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn get_recv_buffer_size(socket: TcpSocket) -> io::Result<u32> {
+    let mut optval: c_int = 0;
+    let mut optlen = size_of::<c_int>() as c_int;
+
+    match unsafe { getsockopt(
+        socket,
+        SOL_SOCKET,
+        winsock2::SO_RCVBUF,
+        &mut optval as *mut _ as *mut _,
+        &mut optlen,
+    ) } {
+        SOCKET_ERROR => Err(io::Error::last_os_error()),
+        _ => Ok(optval as u32),
+    }
+}
+
+pub(crate) fn set_keepalive_params(socket: TcpSocket, keepalive: &TcpKeepalive) -> io::Result<()> {
+    if keepalive.retries.is_some() {
+        // The number of keepalive probes before the connection is dropped is
+        // not configurable on Windows; `SIO_KEEPALIVE_VALS` only takes a time
+        // and an interval.
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "setting the keepalive probe count is not supported on Windows",
+        ));
+    }
+
+    let time_ms = keepalive.time.map(dur_to_ms).unwrap_or(0);
+    let interval_ms = keepalive.interval.map(dur_to_ms).unwrap_or(0);
+
+    let raw_keepalive = mstcpip::tcp_keepalive {
+        onoff: (keepalive.time.is_some() || keepalive.interval.is_some()) as c_ulong,
+        keepalivetime: time_ms,
+        keepaliveinterval: interval_ms,
     };
 
     let mut out = 0;
     match unsafe { WSAIoctl(
         socket,
         mstcpip::SIO_KEEPALIVE_VALS,
-        &keepalive as *const _ as *mut _ as LPVOID,
-        size_of::<mstcpip::tcp_keepalive> as DWORD,
+        &raw_keepalive as *const _ as *mut _ as LPVOID,
+        size_of::<mstcpip::tcp_keepalive>() as DWORD,
         ptr::null_mut() as LPVOID,
         0 as DWORD,
         &mut out as *mut _ as LPVOID,
@@ -182,6 +324,14 @@ pub(crate) fn set_keepalive(socket: TcpSocket, dur: Option<Duration>) -> io::Res
     }
 }
 
+pub(crate) fn set_keepalive(socket: TcpSocket, dur: Option<Duration>) -> io::Result<()> {
+    let keepalive = match dur {
+        Some(dur) => TcpKeepalive::new().with_time(dur).with_interval(dur),
+        None => TcpKeepalive::new(),
+    };
+    set_keepalive_params(socket, &keepalive)
+}
+
 pub(crate) fn get_keepalive(socket: TcpSocket) -> io::Result<Option<Duration>> {
     let mut keepalive = mstcpip::tcp_keepalive {
         onoff: 0,